@@ -0,0 +1,213 @@
+// Copyright 2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Renders an [`Abi`] as the canonical JSON ABI array consumed by ethers, alloy, and
+//! block explorers.
+
+use core::fmt::{self, Write};
+
+use super::model::{Abi, ErrorDef, Event, Function, Param};
+use super::selectors::{array_suffix, normalize_scalar};
+use super::structs::StructRegistry;
+
+/// Renders the ABI as a JSON ABI array into `f`.
+pub fn write_json_abi(abi: &Abi, f: &mut impl Write) -> fmt::Result {
+    let registry = StructRegistry::build(abi);
+
+    write!(f, "[")?;
+
+    let mut first = true;
+    for func in &abi.functions {
+        write_separator(&mut first, f)?;
+        write_function(func, &registry, f)?;
+    }
+    for event in &abi.events {
+        write_separator(&mut first, f)?;
+        write_event(event, &registry, f)?;
+    }
+    for error in &abi.errors {
+        write_separator(&mut first, f)?;
+        write_error(error, &registry, f)?;
+    }
+
+    write!(f, "]")
+}
+
+fn write_separator(first: &mut bool, f: &mut impl Write) -> fmt::Result {
+    if !*first {
+        write!(f, ",")?;
+    }
+    *first = false;
+    Ok(())
+}
+
+fn write_function(func: &Function, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, r#"{{"type":"function","name":"{}","inputs":"#, func.name)?;
+    write_params(&func.inputs, registry, f)?;
+    write!(f, r#","outputs":"#)?;
+    write_params(&func.outputs, registry, f)?;
+    write!(f, r#","stateMutability":"{}"}}"#, func.mutability.as_str())
+}
+
+fn write_event(event: &Event, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, r#"{{"type":"event","name":"{}","inputs":["#, event.name)?;
+    for (i, input) in event.inputs.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        if input.param.components.is_empty() {
+            let sol_type = normalize_scalar(&input.param.sol_type);
+            write!(
+                f,
+                r#"{{"name":"{}","type":"{}","internalType":"{}","indexed":{}}}"#,
+                input.param.name, sol_type, sol_type, input.indexed,
+            )?;
+        } else {
+            let struct_name = registry.name_for(&input.param).unwrap_or("tuple");
+            write!(
+                f,
+                r#"{{"name":"{}","type":"{}","internalType":"struct {}","indexed":{},"components":"#,
+                input.param.name,
+                array_type(&input.param.sol_type, "tuple"),
+                struct_name,
+                input.indexed,
+            )?;
+            write_params(&input.param.components, registry, f)?;
+            write!(f, "}}")?;
+        }
+    }
+    // Stylus has no mechanism for declaring an event `anonymous`, but canonical JSON ABI
+    // event entries always carry the field, so it is always `false` here.
+    write!(f, r#"],"anonymous":false}}"#)
+}
+
+fn write_error(error: &ErrorDef, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, r#"{{"type":"error","name":"{}","inputs":"#, error.name)?;
+    write_params(&error.inputs, registry, f)?;
+    write!(f, "}}")
+}
+
+fn write_params(params: &[Param], registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            write!(f, ",")?;
+        }
+        write_param(param, registry, f)?;
+    }
+    write!(f, "]")
+}
+
+/// Writes a single parameter. Composite params (structs/tuples) get `"type":"tuple"`,
+/// an `internalType` naming the registered struct, and a nested `components` array,
+/// matching the canonical JSON ABI representation of struct types.
+fn write_param(param: &Param, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    if param.components.is_empty() {
+        let sol_type = normalize_scalar(&param.sol_type);
+        write!(
+            f,
+            r#"{{"name":"{}","type":"{}","internalType":"{}"}}"#,
+            param.name, sol_type, sol_type,
+        )?;
+        return Ok(());
+    }
+
+    let struct_name = registry.name_for(param).unwrap_or("tuple");
+    write!(
+        f,
+        r#"{{"name":"{}","type":"{}","internalType":"struct {}","components":"#,
+        param.name,
+        array_type(&param.sol_type, "tuple"),
+        struct_name,
+    )?;
+    write_params(&param.components, registry, f)?;
+    write!(f, "}}")
+}
+
+/// Rewrites a (possibly array-suffixed) composite's base type to `base`, preserving any
+/// trailing `[]`/`[N]` array dimensions, e.g. `(uint256,address)[2][]` -> `tuple[2][]`.
+fn array_type(sol_type: &str, base: &str) -> String {
+    format!("{base}{}", array_suffix(sol_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::model::StateMutability;
+
+    #[test]
+    fn struct_with_an_array_field_renders_a_well_formed_tuple_type() {
+        let batch = Param {
+            name: "batch".into(),
+            sol_type: "(uint256[],address)".into(),
+            struct_name: Some("Batch".into()),
+            components: vec![
+                Param::new("amounts", "uint256[]"),
+                Param::new("to", "address"),
+            ],
+        };
+        let mut abi = Abi::new("Test");
+        abi.functions.push(Function {
+            name: "send".into(),
+            inputs: vec![batch],
+            outputs: vec![],
+            mutability: StateMutability::Nonpayable,
+        });
+
+        let mut out = String::new();
+        write_json_abi(&abi, &mut out).unwrap();
+
+        assert!(out.contains(r#"{"name":"batch","type":"tuple","internalType":"struct Batch""#));
+    }
+
+    #[test]
+    fn event_entries_carry_the_anonymous_field() {
+        let mut abi = Abi::new("Test");
+        abi.events.push(Event {
+            name: "Transfer".into(),
+            inputs: vec![],
+        });
+
+        let mut out = String::new();
+        write_json_abi(&abi, &mut out).unwrap();
+
+        assert!(out.contains(r#""anonymous":false"#));
+    }
+
+    #[test]
+    fn canonical_entries_omit_non_standard_selector_and_topic0_keys() {
+        let mut abi = Abi::new("Test");
+        abi.functions.push(Function {
+            name: "count".into(),
+            inputs: vec![],
+            outputs: vec![],
+            mutability: StateMutability::View,
+        });
+        abi.events.push(Event {
+            name: "Transfer".into(),
+            inputs: vec![],
+        });
+
+        let mut out = String::new();
+        write_json_abi(&abi, &mut out).unwrap();
+
+        assert!(!out.contains("selector"));
+        assert!(!out.contains("topic0"));
+    }
+
+    #[test]
+    fn scalar_types_are_normalized() {
+        let mut abi = Abi::new("Test");
+        abi.functions.push(Function {
+            name: "count".into(),
+            inputs: vec![Param::new("n", "uint")],
+            outputs: vec![],
+            mutability: StateMutability::Nonpayable,
+        });
+
+        let mut out = String::new();
+        write_json_abi(&abi, &mut out).unwrap();
+
+        assert!(out.contains(r#""type":"uint256","internalType":"uint256""#));
+    }
+}
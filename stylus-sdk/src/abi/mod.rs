@@ -0,0 +1,15 @@
+// Copyright 2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Solidity ABI generation and export.
+
+pub mod export;
+pub mod json;
+pub mod model;
+pub mod selectors;
+pub mod solidity;
+pub mod structs;
+
+pub use export::{generate_abi, print_abi, print_json_abi, print_selectors, write_abi, GenerateAbi};
+pub use model::Abi;
+pub use structs::StructRegistry;
@@ -0,0 +1,136 @@
+// Copyright 2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! A structured, in-memory representation of a contract's ABI.
+//!
+//! [`GenerateAbi`](super::GenerateAbi) implementations build one of these trees instead of
+//! writing Solidity or JSON text directly, so the same [`Abi`] can be rendered multiple ways
+//! (see the [`solidity`](super::solidity) and [`json`](super::json) renderers) or inspected
+//! programmatically.
+
+/// The state mutability of a Solidity function, derived from whether the method takes
+/// `&self` or `&mut self` and whether it is marked `#[payable]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateMutability {
+    /// The function reads no state and accepts no value (`pure`).
+    Pure,
+    /// The function reads but does not write state (`view`).
+    View,
+    /// The function may write state and does not accept value (`nonpayable`).
+    Nonpayable,
+    /// The function may write state and accepts value (`payable`).
+    Payable,
+}
+
+impl StateMutability {
+    /// The string Solidity uses for this mutability in a function signature.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StateMutability::Pure => "pure",
+            StateMutability::View => "view",
+            StateMutability::Nonpayable => "nonpayable",
+            StateMutability::Payable => "payable",
+        }
+    }
+}
+
+/// A single function, event, or error parameter, or a field of a struct.
+///
+/// Composite types (structs and tuples) carry their fields in `components`; everything
+/// else leaves `components` empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Param {
+    /// The parameter's name, or an empty string if unnamed.
+    pub name: String,
+    /// The Solidity type, e.g. `uint256` or `(uint256,address)` for an anonymous tuple.
+    pub sol_type: String,
+    /// For tuples and structs, the name of the Rust type that produced this param, if any.
+    /// Used to assign a stable Solidity struct name instead of re-inlining the tuple.
+    pub struct_name: Option<String>,
+    /// The fields of a tuple or struct type. Empty for non-composite types.
+    pub components: Vec<Param>,
+}
+
+impl Param {
+    /// Creates a simple, non-composite parameter.
+    pub fn new(name: impl Into<String>, sol_type: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            sol_type: sol_type.into(),
+            struct_name: None,
+            components: Vec::new(),
+        }
+    }
+}
+
+/// A parameter of an event, which may additionally be `indexed`.
+#[derive(Clone, Debug)]
+pub struct EventParam {
+    /// The underlying parameter.
+    pub param: Param,
+    /// Whether the parameter is part of the event's topics rather than its data.
+    pub indexed: bool,
+}
+
+/// An exported Solidity function.
+#[derive(Clone, Debug)]
+pub struct Function {
+    /// The function's name.
+    pub name: String,
+    /// The function's inputs, in order.
+    pub inputs: Vec<Param>,
+    /// The function's outputs, in order.
+    pub outputs: Vec<Param>,
+    /// The function's state mutability.
+    pub mutability: StateMutability,
+}
+
+/// An exported Solidity event.
+#[derive(Clone, Debug)]
+pub struct Event {
+    /// The event's name.
+    pub name: String,
+    /// The event's inputs, in order.
+    pub inputs: Vec<EventParam>,
+}
+
+/// An exported Solidity custom error.
+#[derive(Clone, Debug)]
+pub struct ErrorDef {
+    /// The error's name.
+    pub name: String,
+    /// The error's inputs, in order.
+    pub inputs: Vec<Param>,
+}
+
+/// A user-defined struct type referenced by one or more functions, events, or errors.
+#[derive(Clone, Debug)]
+pub struct StructDef {
+    /// The struct's Solidity name.
+    pub name: String,
+    /// The struct's fields, in order.
+    pub fields: Vec<Param>,
+}
+
+/// The structured representation of an entire contract ABI.
+#[derive(Clone, Debug, Default)]
+pub struct Abi {
+    /// The interface's name.
+    pub name: String,
+    /// The contract's exported functions.
+    pub functions: Vec<Function>,
+    /// The contract's exported events.
+    pub events: Vec<Event>,
+    /// The contract's exported custom errors.
+    pub errors: Vec<ErrorDef>,
+}
+
+impl Abi {
+    /// Creates an empty ABI for an interface with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+}
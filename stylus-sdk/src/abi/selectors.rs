@@ -0,0 +1,183 @@
+// Copyright 2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Function selectors and event topic hashes, computed from each item's canonical
+//! signature rather than its human-readable display form.
+
+use core::fmt::Write;
+
+use alloy_primitives::keccak256;
+
+use super::model::{Event, Function, Param};
+
+impl Function {
+    /// The function's canonical signature, e.g. `transfer(address,uint256)`, built from
+    /// normalized Solidity types and without the parameter names injected into
+    /// [`write_solidity`](super::solidity::write_solidity)'s output.
+    pub fn canonical_signature(&self) -> String {
+        canonical_signature(&self.name, &self.inputs)
+    }
+
+    /// The 4-byte function selector: the first four bytes of
+    /// `keccak256(canonical_signature)`.
+    pub fn selector(&self) -> [u8; 4] {
+        let hash = keccak256(self.canonical_signature().as_bytes());
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&hash[..4]);
+        selector
+    }
+}
+
+impl Event {
+    /// The event's canonical signature, e.g. `Transfer(address,address,uint256)`.
+    pub fn canonical_signature(&self) -> String {
+        let inputs: Vec<Param> = self.inputs.iter().map(|input| input.param.clone()).collect();
+        canonical_signature(&self.name, &inputs)
+    }
+
+    /// The event's topic0: `keccak256(canonical_signature)`.
+    pub fn topic0(&self) -> [u8; 32] {
+        keccak256(self.canonical_signature().as_bytes()).0
+    }
+}
+
+fn canonical_signature(name: &str, params: &[Param]) -> String {
+    let types: Vec<String> = params.iter().map(canonical_type).collect();
+    format!("{name}({})", types.join(","))
+}
+
+/// The canonical Solidity type of `param`: composite types are expanded to
+/// `(type,type,...)` and bare `uint`/`int` are normalized to their explicit 256-bit form.
+fn canonical_type(param: &Param) -> String {
+    if !param.components.is_empty() {
+        let inner: Vec<String> = param.components.iter().map(canonical_type).collect();
+        return format!("({}){}", inner.join(","), array_suffix(&param.sol_type));
+    }
+    normalize_scalar(&param.sol_type)
+}
+
+/// The trailing array dimensions of a type, e.g. `uint256[2][]` -> `[2][]`.
+///
+/// A composite's `sol_type` is the fully expanded tuple (e.g. `(uint256[],address)[2]`),
+/// so the array suffix may sit after a `[` that belongs to one of the tuple's own fields.
+/// For those, the suffix only begins after the matching top-level `)`; for scalars, the
+/// first `[` is always the array suffix.
+pub(crate) fn array_suffix(sol_type: &str) -> &str {
+    if sol_type.starts_with('(') {
+        let mut depth = 0usize;
+        for (i, byte) in sol_type.bytes().enumerate() {
+            match byte {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return &sol_type[i + 1..];
+                    }
+                }
+                _ => {}
+            }
+        }
+        return "";
+    }
+
+    match sol_type.find('[') {
+        Some(idx) => &sol_type[idx..],
+        None => "",
+    }
+}
+
+/// Normalizes a scalar Solidity type to its canonical form, e.g. `uint` -> `uint256`. Used
+/// both when computing a selector/topic0 and when rendering the JSON ABI, so the emitted
+/// `"type"`/`"internalType"` always agrees with the hash taken over the signature.
+pub(crate) fn normalize_scalar(sol_type: &str) -> String {
+    let (base, suffix) = match sol_type.find('[') {
+        Some(idx) => (&sol_type[..idx], &sol_type[idx..]),
+        None => (sol_type, ""),
+    };
+    let base = match base {
+        "uint" => "uint256",
+        "int" => "int256",
+        other => other,
+    };
+    format!("{base}{suffix}")
+}
+
+/// Renders bytes as a lowercase `0x`-prefixed hex string.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + bytes.len() * 2);
+    hex.push_str("0x");
+    for byte in bytes {
+        write!(hex, "{byte:02x}").expect("formatting into a String cannot fail");
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::model::{EventParam, StateMutability};
+
+    #[test]
+    fn known_function_selector() {
+        let func = Function {
+            name: "transfer".into(),
+            inputs: vec![Param::new("to", "address"), Param::new("amount", "uint256")],
+            outputs: vec![Param::new("", "bool")],
+            mutability: StateMutability::Nonpayable,
+        };
+        assert_eq!(func.canonical_signature(), "transfer(address,uint256)");
+        assert_eq!(to_hex(&func.selector()), "0xa9059cbb");
+    }
+
+    #[test]
+    fn known_event_topic0() {
+        let event = Event {
+            name: "Transfer".into(),
+            inputs: vec![
+                EventParam {
+                    param: Param::new("from", "address"),
+                    indexed: true,
+                },
+                EventParam {
+                    param: Param::new("to", "address"),
+                    indexed: true,
+                },
+                EventParam {
+                    param: Param::new("value", "uint256"),
+                    indexed: false,
+                },
+            ],
+        };
+        assert_eq!(
+            to_hex(&event.topic0()),
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+    }
+
+    #[test]
+    fn array_suffix_skips_brackets_nested_inside_a_tuple() {
+        assert_eq!(array_suffix("(uint256[],address)[2]"), "[2]");
+        assert_eq!(array_suffix("(uint256[],address)"), "");
+        assert_eq!(array_suffix("uint256[2][]"), "[2][]");
+    }
+
+    #[test]
+    fn signature_for_tuple_with_array_field_is_well_formed() {
+        let batch = Param {
+            name: "batch".into(),
+            sol_type: "(uint256[],address)".into(),
+            struct_name: Some("Batch".into()),
+            components: vec![
+                Param::new("amounts", "uint256[]"),
+                Param::new("to", "address"),
+            ],
+        };
+        let func = Function {
+            name: "send".into(),
+            inputs: vec![batch],
+            outputs: vec![],
+            mutability: StateMutability::Nonpayable,
+        };
+        assert_eq!(func.canonical_signature(), "send((uint256[],address))");
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Renders an [`Abi`] as a human-readable Solidity `interface {...}` block.
+
+use core::fmt::{self, Write};
+
+use super::export::underscore_if_sol;
+use super::model::{Abi, ErrorDef, Event, Function, Param};
+use super::selectors::array_suffix;
+use super::structs::StructRegistry;
+
+/// Renders the ABI as a Solidity interface into `f`, with every distinct struct/tuple
+/// type declared once at the top and referenced by name everywhere it appears.
+pub fn write_solidity(abi: &Abi, f: &mut impl Write) -> fmt::Result {
+    let registry = StructRegistry::build(abi);
+
+    writeln!(f, "interface {} {{", abi.name)?;
+
+    for s in registry.structs() {
+        write!(f, "\n    struct {} {{\n", s.name)?;
+        for field in &s.fields {
+            writeln!(
+                f,
+                "        {}{};",
+                type_name(field, &registry),
+                underscore_if_sol(&field.name)
+            )?;
+        }
+        writeln!(f, "    }}")?;
+    }
+
+    for func in &abi.functions {
+        write_function(func, &registry, f)?;
+    }
+    for event in &abi.events {
+        write_event(event, &registry, f)?;
+    }
+    for error in &abi.errors {
+        write_error(error, &registry, f)?;
+    }
+
+    writeln!(f, "}}")
+}
+
+/// The Solidity type to print for `param`: its registered struct name (plus any array
+/// dimensions, e.g. `Point[]`) if it is a composite type, otherwise its plain `sol_type`.
+fn type_name(param: &Param, registry: &StructRegistry) -> String {
+    match registry.name_for(param) {
+        Some(name) => format!("{name}{}", array_suffix(&param.sol_type)),
+        None => param.sol_type.clone(),
+    }
+}
+
+fn write_function(func: &Function, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, "\n    function {}(", func.name)?;
+    write_params(&func.inputs, registry, f)?;
+    write!(f, ") external")?;
+
+    let mutability = func.mutability.as_str();
+    if mutability != "nonpayable" {
+        write!(f, " {mutability}")?;
+    }
+
+    if !func.outputs.is_empty() {
+        write!(f, " returns (")?;
+        write_params(&func.outputs, registry, f)?;
+        write!(f, ")")?;
+    }
+
+    writeln!(f, ";")
+}
+
+fn write_event(event: &Event, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, "\n    event {}(", event.name)?;
+    for (i, input) in event.inputs.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", type_name(&input.param, registry))?;
+        if input.indexed {
+            write!(f, " indexed")?;
+        }
+        write!(f, "{}", underscore_if_sol(&input.param.name))?;
+    }
+    writeln!(f, ");")
+}
+
+fn write_error(error: &ErrorDef, registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    write!(f, "\n    error {}(", error.name)?;
+    write_params(&error.inputs, registry, f)?;
+    writeln!(f, ");")
+}
+
+fn write_params(params: &[Param], registry: &StructRegistry, f: &mut impl Write) -> fmt::Result {
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(
+            f,
+            "{}{}",
+            type_name(param, registry),
+            underscore_if_sol(&param.name)
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::model::StateMutability;
+
+    #[test]
+    fn struct_array_param_keeps_its_array_suffix() {
+        let point_field = Param::new("x", "uint256");
+        let points = Param {
+            name: "batch".into(),
+            sol_type: "(uint256,uint256)[]".into(),
+            struct_name: Some("Point".into()),
+            components: vec![point_field, Param::new("y", "uint256")],
+        };
+        let mut abi = Abi::new("Test");
+        abi.functions.push(Function {
+            name: "record".into(),
+            inputs: vec![points],
+            outputs: vec![],
+            mutability: StateMutability::Nonpayable,
+        });
+
+        let mut out = String::new();
+        write_solidity(&abi, &mut out).unwrap();
+
+        assert!(out.contains("function record(Point[] batch) external;"));
+    }
+}
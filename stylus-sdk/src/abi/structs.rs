@@ -0,0 +1,178 @@
+// Copyright 2023, Offchain Labs, Inc.
+// For licensing, see https://github.com/OffchainLabs/stylus-sdk-rs/blob/stylus/licenses/COPYRIGHT.md
+
+//! Collects and names the distinct struct/tuple types referenced across an ABI.
+//!
+//! Without this, a Rust struct shared by several methods would be re-inlined as an
+//! anonymous tuple everywhere it appears, losing its `internalType` and forcing readers
+//! to diff tuples by hand. [`StructRegistry`] walks the ABI once, assigns each distinct
+//! composite type a stable Solidity `struct Name { ... }` declaration, and lets renderers
+//! reference it by name instead. This is analogous to ethers' `InternalStructs`.
+
+use super::model::{Abi, Param, StructDef};
+
+/// A registry of the named struct types referenced by an [`Abi`], built with [`Self::build`].
+#[derive(Default)]
+pub struct StructRegistry {
+    /// The registered structs, in dependency order: a struct's own dependencies (nested
+    /// structs) always appear before it.
+    structs: Vec<StructDef>,
+    /// Every distinct composite param seen during the walk, alongside the name it was
+    /// assigned. Used to look the name back up when rendering.
+    assignments: Vec<(Option<String>, Vec<Param>, String)>,
+}
+
+impl StructRegistry {
+    /// Walks every function, event, and error in `abi` and builds the registry.
+    pub fn build(abi: &Abi) -> Self {
+        let mut registry = Self::default();
+        for func in &abi.functions {
+            for param in func.inputs.iter().chain(&func.outputs) {
+                registry.visit(param);
+            }
+        }
+        for event in &abi.events {
+            for input in &event.inputs {
+                registry.visit(&input.param);
+            }
+        }
+        for error in &abi.errors {
+            for param in &error.inputs {
+                registry.visit(param);
+            }
+        }
+        registry
+    }
+
+    /// The registered struct declarations, in the order they should be emitted so that
+    /// every dependency is declared before the struct that uses it.
+    pub fn structs(&self) -> &[StructDef] {
+        &self.structs
+    }
+
+    /// Returns the Solidity name assigned to a composite param (a struct or tuple), if any
+    /// was registered for it. Returns `None` for non-composite params.
+    pub fn name_for(&self, param: &Param) -> Option<&str> {
+        if param.components.is_empty() {
+            return None;
+        }
+        self.assignments
+            .iter()
+            .find(|(struct_name, fields, _)| {
+                *struct_name == param.struct_name && *fields == param.components
+            })
+            .map(|(.., name)| name.as_str())
+    }
+
+    fn visit(&mut self, param: &Param) {
+        if param.components.is_empty() {
+            return;
+        }
+
+        // Dependencies (nested structs) must be declared before this one.
+        for field in &param.components {
+            self.visit(field);
+        }
+
+        if self.name_for(param).is_some() {
+            return;
+        }
+
+        let base_name = param
+            .struct_name
+            .clone()
+            .unwrap_or_else(|| "Struct".to_string());
+
+        // Suffix on collision with a type of the same name but a different shape.
+        let mut name = base_name.clone();
+        let mut suffix = 2;
+        while self.structs.iter().any(|s| s.name == name) {
+            name = format!("{base_name}{suffix}");
+            suffix += 1;
+        }
+
+        self.structs.push(StructDef {
+            name: name.clone(),
+            fields: param.components.clone(),
+        });
+        self.assignments
+            .push((param.struct_name.clone(), param.components.clone(), name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abi::model::{Function, StateMutability};
+
+    fn point(name: &str) -> Param {
+        Param {
+            name: name.into(),
+            sol_type: "(uint256,uint256)".into(),
+            struct_name: Some("Point".into()),
+            components: vec![Param::new("x", "uint256"), Param::new("y", "uint256")],
+        }
+    }
+
+    fn func(name: &str, inputs: Vec<Param>, outputs: Vec<Param>) -> Function {
+        Function {
+            name: name.into(),
+            inputs,
+            outputs,
+            mutability: StateMutability::View,
+        }
+    }
+
+    #[test]
+    fn identical_struct_is_reused_across_functions() {
+        let mut abi = Abi::new("Test");
+        abi.functions.push(func("a", vec![point("p")], vec![]));
+        abi.functions.push(func("b", vec![], vec![point("p")]));
+
+        let registry = StructRegistry::build(&abi);
+        assert_eq!(registry.structs().len(), 1);
+        assert_eq!(registry.structs()[0].name, "Point");
+    }
+
+    #[test]
+    fn name_collision_with_a_different_shape_is_suffixed() {
+        let different_point = Param {
+            name: "p".into(),
+            sol_type: "(address,uint256)".into(),
+            struct_name: Some("Point".into()),
+            components: vec![
+                Param::new("owner", "address"),
+                Param::new("balance", "uint256"),
+            ],
+        };
+        let mut abi = Abi::new("Test");
+        abi.functions.push(func("a", vec![point("p")], vec![]));
+        abi.functions
+            .push(func("b", vec![different_point], vec![]));
+
+        let registry = StructRegistry::build(&abi);
+        let names: Vec<&str> = registry.structs().iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Point", "Point2"]);
+    }
+
+    #[test]
+    fn nested_struct_is_declared_before_its_dependent() {
+        let mut bottom_left = point("bottomLeft");
+        bottom_left.name = "bottomLeft".into();
+        let mut top_right = point("topRight");
+        top_right.name = "topRight".into();
+
+        let region = Param {
+            name: "region".into(),
+            sol_type: "((uint256,uint256),(uint256,uint256))".into(),
+            struct_name: Some("Region".into()),
+            components: vec![bottom_left, top_right],
+        };
+        let mut abi = Abi::new("Test");
+        abi.functions.push(func("a", vec![region], vec![]));
+
+        let registry = StructRegistry::build(&abi);
+        let names: Vec<&str> = registry.structs().iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Point", "Region"]);
+    }
+}